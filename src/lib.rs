@@ -3,6 +3,7 @@
 //! - Assert that an item was dropped
 //! - Assert that an item was not dropped
 //! - Assert that an item was not dropped multiple times (this is implicit tested)
+//! - Optionally, assert that an item was dropped more than once (a double drop)
 //!
 //! This kind of test is useful for objects that manages the lifetime of other objects, like smart
 //! pointers and containers.
@@ -59,17 +60,119 @@
 //! assert_eq!(10, td.num_tracked_items());
 //! assert_eq!(10, td.num_dropped_items());
 //! ```
+//!
+//! Attach a payload to an item to correlate a drop with some data, for example the expected
+//! position in the drop order.
+//!
+//! ```
+//! use testdrop::TestDrop;
+//!
+//! let td = TestDrop::new();
+//! let (id, item) = td.new_item_with("first");
+//!
+//! assert_eq!(&"first", item.value());
+//!
+//! drop(item);
+//! td.assert_drop(id);
+//! ```
+//!
+//! Test that sibling items are still dropped while unwinding from a panicking destructor.
+//!
+//! ```
+//! use testdrop::TestDrop;
+//! use std::panic;
+//!
+//! let td = TestDrop::new();
+//! let (failing_id, failing_item) = td.new_failing_item();
+//! let (sibling_id, sibling_item) = td.new_item();
+//!
+//! let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+//!     drop(failing_item);
+//!     drop(sibling_item);
+//! }));
+//!
+//! assert!(result.is_err());
+//! td.assert_drop(failing_id);
+//! td.assert_drop(sibling_id);
+//! ```
+//!
+//! Confirm that an unsafe container drops a value twice, instead of panicking on the second
+//! drop. This needs [`new_item_allow_double_drop`](struct.TestDrop.html#method.new_item_allow_double_drop),
+//! since a plain [`new_item`](struct.TestDrop.html#method.new_item) panics as soon as it is
+//! dropped a second time.
+//!
+//! ```
+//! use testdrop::TestDrop;
+//! use std::ptr;
+//!
+//! let td = TestDrop::new();
+//! let (id, item) = td.new_item_allow_double_drop();
+//!
+//! // Simulate a container that reads the value out twice, e.g. via `ptr::read`.
+//! unsafe { ptr::read(&item as *const _) };
+//! drop(item);
+//!
+//! td.assert_double_drop(id);
+//! assert_eq!(2, td.drop_count(id));
+//! ```
+//!
+//! Test that struct fields are dropped in declaration order.
+//!
+//! ```
+//! use testdrop::TestDrop;
+//!
+//! struct Pair<'a> {
+//!     first: testdrop::Item<'a>,
+//!     second: testdrop::Item<'a>,
+//! }
+//!
+//! let td = TestDrop::new();
+//! let (first, first_item) = td.new_item();
+//! let (second, second_item) = td.new_item();
+//! let pair = Pair {
+//!     first: first_item,
+//!     second: second_item,
+//! };
+//!
+//! drop(pair);
+//!
+//! // Fields are dropped in declaration order.
+//! td.assert_drop_order(&[first, second]);
+//! ```
+//!
+//! Test that a value moved into a spawned thread is dropped when the thread finishes. This needs
+//! [`SyncTestDrop`](struct.SyncTestDrop.html), since [`TestDrop`](struct.TestDrop.html)'s
+//! [`Item`](struct.Item.html) is not `Send`.
+//!
+//! ```
+//! use testdrop::SyncTestDrop;
+//! use std::thread;
+//!
+//! let td = SyncTestDrop::new();
+//! let (id, item) = td.new_item();
+//!
+//! thread::scope(|s| {
+//!     s.spawn(move || {
+//!         drop(item);
+//!     });
+//! });
+//!
+//! td.assert_drop(id);
+//! ```
 
-use std::cell::{Cell, RefCell};
+use std::cell::RefCell;
 use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
 
 /// A struct to help test drop related issues.
 ///
 /// See the [module](index.html) documentation for examples of usage.
 #[derive(Default, Debug)]
 pub struct TestDrop {
-    drops: Cell<usize>,
-    is_dropped: RefCell<Vec<bool>>,
+    drop_count: RefCell<Vec<usize>>,
+    allow_double_drop: RefCell<Vec<bool>>,
+    drop_order: RefCell<Vec<usize>>,
 }
 
 impl TestDrop {
@@ -82,19 +185,59 @@ impl TestDrop {
     /// The `id` of the item can be used with [`assert_drop`](struct.TestDrop#tymethod.assert_drop)
     /// and [`assert_no_drop`](struct.TestDrop#tymethod.assert_no_drop).
     pub fn new_item(&self) -> (usize, Item) {
-        let id = self.num_tracked_items();
-        self.is_dropped.borrow_mut().push(false);
-        (id, Item::new(id, self))
+        self.new_item_with(())
+    }
+
+    /// Creates a new [`Item`](struct.Item.html) carrying `value` as a payload, and returns the
+    /// `id` of the item and the item. This is useful to correlate a drop with some data, for
+    /// example the index at which an item is expected to be dropped.
+    ///
+    /// The `id` of the item can be used with [`assert_drop`](struct.TestDrop.html#method.assert_drop)
+    /// and [`assert_no_drop`](struct.TestDrop.html#method.assert_no_drop), the same as
+    /// [`new_item`](struct.TestDrop.html#method.new_item).
+    pub fn new_item_with<T>(&self, value: T) -> (usize, Item<T>) {
+        let id = self.register(false);
+        (id, Item::new(id, self, value))
+    }
+
+    /// Creates a new [`Item`](struct.Item.html) whose `Drop::drop` records the drop and then
+    /// panics, and returns the `id` of the item and the item. This is useful to test that sibling
+    /// values are still dropped while unwinding from a panicking destructor, typically under [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html).
+    ///
+    /// The drop is still recorded before the panic, so the `id` of the item can be used with
+    /// [`assert_drop`](struct.TestDrop.html#method.assert_drop) and in the
+    /// [drop order](struct.TestDrop.html#method.drop_order), the same as
+    /// [`new_item`](struct.TestDrop.html#method.new_item).
+    pub fn new_failing_item(&self) -> (usize, Item) {
+        let id = self.register(false);
+        (id, Item::new_failing(id, self, ()))
+    }
+
+    /// Creates a new [`Item`](struct.Item.html) that tolerates being dropped more than once, and
+    /// returns the `id` of the item and the item. Unlike [`new_item`](struct.TestDrop.html#method.new_item),
+    /// whose drop panics as soon as it is dropped a second time, a later drop of this item is
+    /// recorded instead of panicking, so [`drop_count`](struct.TestDrop.html#method.drop_count)
+    /// and [`assert_double_drop`](struct.TestDrop.html#method.assert_double_drop) can confirm it
+    /// happened. This is useful to test unsafe containers or `ptr::read`-based code paths where
+    /// the goal is to confirm that a double free was observed, rather than to blow up the test
+    /// harness.
+    pub fn new_item_allow_double_drop(&self) -> (usize, Item) {
+        let id = self.register(true);
+        (id, Item::new(id, self, ()))
     }
 
     /// Returns the number of tracked items.
     pub fn num_tracked_items(&self) -> usize {
-        self.is_dropped.borrow().len()
+        self.drop_count.borrow().len()
     }
 
     /// Returns the number of dropped items so far.
     pub fn num_dropped_items(&self) -> usize {
-        self.drops.get()
+        self.drop_count
+            .borrow()
+            .iter()
+            .filter(|&&count| count > 0)
+            .count()
     }
 
     /// Asserts that an item was dropped.
@@ -119,51 +262,148 @@ impl TestDrop {
         );
     }
 
+    /// Returns the order in which items were dropped, as the sequence of their `id`s.
+    pub fn drop_order(&self) -> Vec<usize> {
+        self.drop_order.borrow().clone()
+    }
+
+    /// Asserts that the item `a` was dropped before the item `b`.
+    ///
+    /// # Panics
+    ///
+    /// If `a` was not dropped, `b` was not dropped, or `a` was dropped after `b`.
+    pub fn assert_dropped_before(&self, a: usize, b: usize) {
+        let order = self.drop_order.borrow();
+        let position_of = |id| {
+            order
+                .iter()
+                .position(|&dropped| dropped == id)
+                .unwrap_or_else(|| panic!("{} should be dropped, but was not", id))
+        };
+        assert!(
+            position_of(a) < position_of(b),
+            "{} should be dropped before {}, but was not",
+            a,
+            b
+        );
+    }
+
+    /// Asserts that the recorded drop order is exactly `ids`.
+    ///
+    /// # Panics
+    ///
+    /// If the recorded drop order does not equal `ids`.
+    pub fn assert_drop_order(&self, ids: &[usize]) {
+        assert_eq!(
+            ids,
+            &self.drop_order.borrow()[..],
+            "drop order should be {:?}, but was {:?}",
+            ids,
+            self.drop_order.borrow()
+        );
+    }
+
+    /// Returns the number of times an item was dropped.
+    pub fn drop_count(&self, id: usize) -> usize {
+        self.drop_count.borrow()[id]
+    }
+
+    /// Asserts that an item was dropped more than once. Only items created with
+    /// [`new_item_allow_double_drop`](struct.TestDrop.html#method.new_item_allow_double_drop) can
+    /// satisfy this, since any other item panics as soon as it is dropped a second time.
+    ///
+    /// # Panics
+    ///
+    /// If the item was dropped once or not at all.
+    pub fn assert_double_drop(&self, id: usize) {
+        assert!(
+            self.drop_count(id) >= 2,
+            "{} should be dropped more than once, but was dropped {} time(s)",
+            id,
+            self.drop_count(id)
+        );
+    }
+
+    /// Returns the number of tracked items that were dropped more than once.
+    pub fn num_double_dropped_items(&self) -> usize {
+        self.drop_count
+            .borrow()
+            .iter()
+            .filter(|&&count| count >= 2)
+            .count()
+    }
+
+    fn register(&self, allow_double_drop: bool) -> usize {
+        let id = self.num_tracked_items();
+        self.drop_count.borrow_mut().push(0);
+        self.allow_double_drop.borrow_mut().push(allow_double_drop);
+        id
+    }
+
     fn is_dropped(&self, id: usize) -> bool {
-        self.is_dropped.borrow()[id]
+        self.drop_count(id) > 0
     }
 
     fn add_drop(&self, id: usize) {
-        if self.is_dropped(id) {
+        let mut drop_count = self.drop_count.borrow_mut();
+        if drop_count[id] > 0 && !self.allow_double_drop.borrow()[id] {
             panic!("{:?} is already dropped", id)
         }
-        self.is_dropped.borrow_mut()[id] = true;
-        self.drops.set(self.num_dropped_items() + 1);
+        drop_count[id] += 1;
+        drop(drop_count);
+        self.drop_order.borrow_mut().push(id);
     }
 }
 
 /// An item tracked by `TestDrop`.
 ///
-/// This `struct` is created by [`TestDrop::new_item`](struct.TestDrop.html). See its documentation
+/// This `struct` is created by [`TestDrop::new_item`](struct.TestDrop.html) and
+/// [`TestDrop::new_item_with`](struct.TestDrop.html#method.new_item_with). See its documentation
 /// for more.
-pub struct Item<'a> {
+pub struct Item<'a, T = ()> {
     id: usize,
     parent: &'a TestDrop,
+    value: T,
+    should_panic: bool,
 }
 
-impl<'a> fmt::Debug for Item<'a> {
+impl<'a, T> fmt::Debug for Item<'a, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Item {{ id: {} }}", self.id)
     }
 }
 
-impl<'a> PartialEq for Item<'a> {
+impl<'a, T> PartialEq for Item<'a, T> {
     fn eq(&self, other: &Self) -> bool {
         self.id() == other.id() && self.parent as *const _ == other.parent as *const _
     }
 }
 
-impl<'a> Drop for Item<'a> {
+impl<'a, T> Drop for Item<'a, T> {
     fn drop(&mut self) {
-        self.parent.add_drop(self.id)
+        self.parent.add_drop(self.id);
+        if self.should_panic {
+            panic!("{} panicked on drop", self.id);
+        }
     }
 }
 
-impl<'a> Item<'a> {
-    fn new(id: usize, parent: &'a TestDrop) -> Self {
+impl<'a, T> Item<'a, T> {
+    fn new(id: usize, parent: &'a TestDrop, value: T) -> Self {
+        Item {
+            id,
+            parent,
+            value,
+            should_panic: false,
+        }
+    }
+
+    fn new_failing(id: usize, parent: &'a TestDrop, value: T) -> Self {
         Item {
-            id: id,
-            parent: parent,
+            id,
+            parent,
+            value,
+            should_panic: true,
         }
     }
 
@@ -171,6 +411,133 @@ impl<'a> Item<'a> {
     pub fn id(&self) -> usize {
         self.id
     }
+
+    /// Returns a reference to the value carried by this item.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the value carried by this item.
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+/// A thread-safe version of [`TestDrop`](struct.TestDrop.html), useful to test drops that happen
+/// in another thread.
+///
+/// Unlike `TestDrop`, `SyncTestDrop` has no opt-in for double drops: every item always panics as
+/// soon as it is dropped a second time.
+///
+/// See the [module](index.html) documentation for examples of usage.
+#[derive(Default, Debug)]
+pub struct SyncTestDrop {
+    drops: AtomicUsize,
+    is_dropped: Mutex<Vec<AtomicBool>>,
+}
+
+impl SyncTestDrop {
+    /// Creates a new `SyncTestDrop`.
+    pub fn new() -> SyncTestDrop {
+        SyncTestDrop::default()
+    }
+
+    /// Creates a new [`SyncItem`](struct.SyncItem.html) and returns the `id` of the item and the
+    /// item. The `id` of the item can be used with
+    /// [`assert_drop`](struct.SyncTestDrop.html#method.assert_drop) and
+    /// [`assert_no_drop`](struct.SyncTestDrop.html#method.assert_no_drop).
+    pub fn new_item(&self) -> (usize, SyncItem) {
+        let mut is_dropped = self.is_dropped.lock().unwrap();
+        let id = is_dropped.len();
+        is_dropped.push(AtomicBool::new(false));
+        drop(is_dropped);
+        (id, SyncItem::new(id, self))
+    }
+
+    /// Returns the number of tracked items.
+    pub fn num_tracked_items(&self) -> usize {
+        self.is_dropped.lock().unwrap().len()
+    }
+
+    /// Returns the number of dropped items so far.
+    pub fn num_dropped_items(&self) -> usize {
+        self.drops.load(Ordering::SeqCst)
+    }
+
+    /// Asserts that an item was dropped.
+    ///
+    /// # Panics
+    ///
+    /// If the item was not dropped.
+    pub fn assert_drop(&self, id: usize) {
+        assert!(self.is_dropped(id), "{} should be dropped, but was not", id);
+    }
+
+    /// Asserts that an item was not dropped.
+    ///
+    /// # Panics
+    ///
+    /// If the item was dropped.
+    pub fn assert_no_drop(&self, id: usize) {
+        assert!(
+            !self.is_dropped(id),
+            "{} should not be dropped, but was",
+            id
+        );
+    }
+
+    fn is_dropped(&self, id: usize) -> bool {
+        self.is_dropped.lock().unwrap()[id].load(Ordering::SeqCst)
+    }
+
+    fn add_drop(&self, id: usize) {
+        let is_dropped = self.is_dropped.lock().unwrap();
+        let was_dropped = is_dropped[id].swap(true, Ordering::SeqCst);
+        drop(is_dropped);
+        if was_dropped {
+            panic!("{:?} is already dropped", id)
+        }
+        self.drops.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// An item tracked by `SyncTestDrop`.
+///
+/// This `struct` is created by [`SyncTestDrop::new_item`](struct.SyncTestDrop.html). See its
+/// documentation for more. Unlike [`Item`](struct.Item.html), `SyncItem` is `Send` and `Sync`, so
+/// it can be moved into another thread.
+pub struct SyncItem<'a> {
+    id: usize,
+    parent: &'a SyncTestDrop,
+}
+
+impl<'a> fmt::Debug for SyncItem<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SyncItem {{ id: {} }}", self.id)
+    }
+}
+
+impl<'a> PartialEq for SyncItem<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id() && self.parent as *const _ == other.parent as *const _
+    }
+}
+
+impl<'a> Drop for SyncItem<'a> {
+    fn drop(&mut self) {
+        self.parent.add_drop(self.id)
+    }
+}
+
+impl<'a> SyncItem<'a> {
+    fn new(id: usize, parent: &'a SyncTestDrop) -> Self {
+        SyncItem { id, parent }
+    }
+
+    /// Returns the `id` of this item.
+    pub fn id(&self) -> usize {
+        self.id
+    }
 }
 
 #[cfg(test)]
@@ -206,6 +573,38 @@ mod tests {
         unsafe { ::std::ptr::read(&a as *const _) };
     }
 
+    #[test]
+    fn drop_more_than_once_with_allow_double_drop() {
+        let td = TestDrop::new();
+        let (id, a) = td.new_item_allow_double_drop();
+        unsafe { ::std::ptr::read(&a as *const _) };
+        drop(a);
+
+        td.assert_double_drop(id);
+        assert_eq!(2, td.drop_count(id));
+        assert_eq!(1, td.num_double_dropped_items());
+    }
+
+    #[test]
+    fn drop_count_of_single_drop() {
+        let td = TestDrop::new();
+        let (id, item) = td.new_item();
+        assert_eq!(0, td.drop_count(id));
+
+        drop(item);
+        assert_eq!(1, td.drop_count(id));
+        assert_eq!(0, td.num_double_dropped_items());
+    }
+
+    #[test]
+    #[should_panic(expected = "0 should be dropped more than once, but was dropped 1 time(s)")]
+    fn assert_double_drop_panics_on_single_drop() {
+        let td = TestDrop::new();
+        let (id, item) = td.new_item();
+        drop(item);
+        td.assert_double_drop(id);
+    }
+
     #[test]
     fn count() {
         let td = TestDrop::new();
@@ -242,10 +641,226 @@ mod tests {
         assert_ne!(i2, i3);
     }
 
+    #[test]
+    fn drop_order() {
+        let td = TestDrop::new();
+        let (a, a_item) = td.new_item();
+        let (b, b_item) = td.new_item();
+        let (c, c_item) = td.new_item();
+
+        drop(b_item);
+        drop(c_item);
+        drop(a_item);
+
+        assert_eq!(vec![b, c, a], td.drop_order());
+        td.assert_dropped_before(b, c);
+        td.assert_dropped_before(c, a);
+        td.assert_dropped_before(b, a);
+        td.assert_drop_order(&[b, c, a]);
+    }
+
+    #[test]
+    #[should_panic(expected = "0 should be dropped, but was not")]
+    fn assert_dropped_before_panics_when_not_dropped() {
+        let td = TestDrop::new();
+        let (a, _a_item) = td.new_item();
+        let (b, b_item) = td.new_item();
+        drop(b_item);
+        td.assert_dropped_before(a, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "0 should be dropped before 1, but was not")]
+    fn assert_dropped_before_panics_when_order_is_wrong() {
+        let td = TestDrop::new();
+        let (a, a_item) = td.new_item();
+        let (b, b_item) = td.new_item();
+        drop(b_item);
+        drop(a_item);
+        td.assert_dropped_before(a, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "drop order should be")]
+    fn assert_drop_order_panics_when_order_is_wrong() {
+        let td = TestDrop::new();
+        let (a, a_item) = td.new_item();
+        let (b, b_item) = td.new_item();
+        drop(a_item);
+        drop(b_item);
+        td.assert_drop_order(&[b, a]);
+    }
+
     #[test]
     fn item_debug() {
         let td = TestDrop::new();
         let (a, item) = td.new_item();
         assert!(format!("{:?}", item).contains(&format!("id: {}", a)));
     }
+
+    #[test]
+    fn new_item_with_value() {
+        let td = TestDrop::new();
+        let (id, mut item) = td.new_item_with("a");
+        assert_eq!(&"a", item.value());
+
+        *item.value_mut() = "b";
+        assert_eq!(&"b", item.value());
+
+        drop(item);
+        td.assert_drop(id);
+    }
+
+    #[test]
+    fn new_item_is_new_item_with_unit() {
+        let td = TestDrop::new();
+        let (id, item) = td.new_item();
+        assert_eq!(&(), item.value());
+        drop(item);
+        td.assert_drop(id);
+    }
+
+    #[test]
+    #[should_panic(expected = "0 panicked on drop")]
+    fn failing_item_panics_on_drop() {
+        let td = TestDrop::new();
+        let (id, item) = td.new_failing_item();
+        drop(item);
+        td.assert_drop(id);
+        unreachable!();
+    }
+
+    #[test]
+    fn failing_item_drops_siblings_on_unwind() {
+        use std::panic;
+
+        let td = TestDrop::new();
+        let (failing_id, failing_item) = td.new_failing_item();
+        let (sibling_id, sibling_item) = td.new_item();
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            drop(failing_item);
+            drop(sibling_item);
+        }));
+
+        assert!(result.is_err());
+        td.assert_drop(failing_id);
+        td.assert_drop(sibling_id);
+        assert_eq!(2, td.num_dropped_items());
+    }
+
+    #[test]
+    #[should_panic(expected = "0 should be dropped, but was not")]
+    fn sync_assert_drop() {
+        let td = SyncTestDrop::new();
+        let (id, _item) = td.new_item();
+        td.assert_drop(id);
+        unreachable!();
+    }
+
+    #[test]
+    #[should_panic(expected = "0 should not be dropped, but was")]
+    fn sync_assert_no_drop() {
+        let td = SyncTestDrop::new();
+        let (id, item) = td.new_item();
+        td.assert_no_drop(id);
+        drop(item);
+        td.assert_drop(id);
+        td.assert_no_drop(id);
+        unreachable!();
+    }
+
+    #[test]
+    #[should_panic(expected = "0 is already dropped")]
+    fn sync_drop_more_than_once() {
+        let td = SyncTestDrop::new();
+        let (_, a) = td.new_item();
+        unsafe { ::std::ptr::read(&a as *const _) };
+    }
+
+    #[test]
+    fn sync_drop_more_than_once_does_not_poison_mutex() {
+        use std::panic;
+
+        let td = SyncTestDrop::new();
+        let (id, a) = td.new_item();
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            unsafe { ::std::ptr::read(&a as *const _) };
+            drop(a);
+        }));
+        assert!(result.is_err());
+
+        td.assert_drop(id);
+        assert_eq!(1, td.num_tracked_items());
+    }
+
+    #[test]
+    fn sync_count() {
+        let td = SyncTestDrop::new();
+        assert_eq!(0, td.num_tracked_items());
+        assert_eq!(0, td.num_dropped_items());
+
+        let (_, a) = td.new_item();
+        let (_, b) = td.new_item();
+        assert_eq!(2, td.num_tracked_items());
+        assert_eq!(0, td.num_dropped_items());
+
+        drop(a);
+        assert_eq!(2, td.num_tracked_items());
+        assert_eq!(1, td.num_dropped_items());
+
+        drop(b);
+        assert_eq!(2, td.num_tracked_items());
+        assert_eq!(2, td.num_dropped_items());
+    }
+
+    #[test]
+    fn sync_item_eq() {
+        let td1 = SyncTestDrop::new();
+        let (_, i1) = td1.new_item();
+
+        let td2 = SyncTestDrop::new();
+        let (_, i2) = td2.new_item();
+        let (_, i3) = td2.new_item();
+
+        assert_eq!(i1, i1);
+        assert_eq!(i2, i2);
+        assert_ne!(i1, i2);
+        assert_ne!(i2, i1);
+        assert_ne!(i2, i3);
+    }
+
+    #[test]
+    fn sync_item_debug() {
+        let td = SyncTestDrop::new();
+        let (a, item) = td.new_item();
+        assert!(format!("{:?}", item).contains(&format!("id: {}", a)));
+    }
+
+    #[test]
+    fn sync_item_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SyncItem>();
+    }
+
+    #[test]
+    fn sync_drop_across_thread() {
+        use std::thread;
+
+        let td = SyncTestDrop::new();
+        let (a, a_item) = td.new_item();
+        let (b, b_item) = td.new_item();
+
+        thread::scope(|s| {
+            s.spawn(move || {
+                drop(a_item);
+                drop(b_item);
+            });
+        });
+
+        td.assert_drop(a);
+        td.assert_drop(b);
+        assert_eq!(2, td.num_dropped_items());
+    }
 }